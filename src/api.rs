@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Json};
+use axum::routing::{get, post};
+use axum::Router;
+use tokio::sync::RwLock;
+
+use crate::registry::{RunContext, TaskStats};
+use crate::user_service::{User, UserStore};
+use crate::TestRegistry;
+
+/// Shared state threaded through every handler via the typed `State`
+/// extractor, so a missing or mistyped dependency fails at build time
+/// rather than at request time as `Extension` would.
+#[derive(Clone)]
+pub struct AppState {
+    pub users: Arc<dyn UserStore>,
+    pub registry: Arc<RwLock<TestRegistry>>,
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/users/:id", get(get_user))
+        .route("/runs", post(run_all))
+        .with_state(state)
+}
+
+async fn health() -> impl IntoResponse {
+    StatusCode::OK
+}
+
+async fn get_user(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<User>, StatusCode> {
+    state
+        .users
+        .get_user(&id)
+        .await
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn run_all(State(state): State<AppState>) -> Json<std::collections::HashMap<String, TaskStats>> {
+    let ctx = RunContext {
+        environment: "default".to_string(),
+    };
+    let stats = state.registry.read().await.run_all(&ctx).await;
+    Json(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    use crate::user_service::MockUserStore;
+
+    fn test_state(users: impl UserStore + 'static) -> AppState {
+        AppState {
+            users: Arc::new(users),
+            registry: Arc::new(RwLock::new(TestRegistry::new())),
+        }
+    }
+
+    #[tokio::test]
+    async fn health_check_returns_ok() {
+        let app = router(test_state(MockUserStore::new()));
+
+        let response = app
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn get_user_returns_mocked_user() {
+        let mut mock = MockUserStore::new();
+        mock.expect_get_user().returning(|_| {
+            Some(User {
+                id: "1".to_string(),
+                name: "Ada".to_string(),
+            })
+        });
+        let app = router(test_state(mock));
+
+        let response = app
+            .oneshot(Request::builder().uri("/users/1").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let user: User = serde_json::from_slice(&body).unwrap();
+        assert_eq!(user.name, "Ada");
+    }
+
+    #[tokio::test]
+    async fn get_user_returns_404_when_missing() {
+        let mut mock = MockUserStore::new();
+        mock.expect_get_user().returning(|_| None);
+        let app = router(test_state(mock));
+
+        let response = app
+            .oneshot(Request::builder().uri("/users/missing").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn run_all_returns_collected_stats() {
+        let app = router(test_state(MockUserStore::new()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/runs")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let stats: std::collections::HashMap<String, serde_json::Value> =
+            serde_json::from_slice(&body).unwrap();
+        assert!(stats.is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use base64::Engine;
+use reqwest::{Client, Method};
+
+/// Cookies captured from `Set-Cookie` responses, keyed by cookie name,
+/// and replayed on every subsequent request.
+#[derive(Default)]
+struct CookieJar {
+    cookies: HashMap<String, String>,
+}
+
+impl CookieJar {
+    fn capture(&mut self, headers: &reqwest::header::HeaderMap) {
+        for value in headers.get_all(reqwest::header::SET_COOKIE) {
+            let Ok(raw) = value.to_str() else { continue };
+            let Some(pair) = raw.split(';').next() else { continue };
+            if let Some((name, value)) = pair.split_once('=') {
+                self.cookies.insert(name.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    fn header(&self) -> Option<String> {
+        if self.cookies.is_empty() {
+            return None;
+        }
+        Some(
+            self.cookies
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+}
+
+/// A stateful HTTP client that persists state across calls the way a
+/// browser session would: a pooled `reqwest::Client` for keep-alive
+/// reuse, and a cookie jar that captures `Set-Cookie` responses and
+/// replays them on every subsequent request. Enables multi-step
+/// authenticated test flows (login -> access protected page) without
+/// the task author manually threading session state.
+pub struct HttpAgent {
+    client: Client,
+    jar: Mutex<CookieJar>,
+    default_headers: Mutex<HashMap<String, String>>,
+}
+
+impl HttpAgent {
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            jar: Mutex::new(CookieJar::default()),
+            default_headers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the `Authorization` header to HTTP basic auth for every
+    /// request made through this agent from now on.
+    ///
+    /// Unlike `get`/`post`, this isn't scoped to a single request: it
+    /// mutates the agent's shared default headers immediately, so it
+    /// affects every request made through this agent afterwards rather
+    /// than returning a builder for one call.
+    pub fn auth(&self, user: &str, pass: &str) -> &Self {
+        let credentials = base64::engine::general_purpose::STANDARD.encode(format!("{user}:{pass}"));
+        self.default_headers
+            .lock()
+            .unwrap()
+            .insert("Authorization".to_string(), format!("Basic {credentials}"));
+        self
+    }
+
+    pub fn get<'a>(&'a self, url: &str) -> RequestBuilder<'a> {
+        RequestBuilder::new(self, Method::GET, url)
+    }
+
+    pub fn post<'a>(&'a self, url: &str) -> RequestBuilder<'a> {
+        RequestBuilder::new(self, Method::POST, url)
+    }
+}
+
+impl Default for HttpAgent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds a single request against an `HttpAgent`'s shared session state.
+pub struct RequestBuilder<'a> {
+    agent: &'a HttpAgent,
+    method: Method,
+    url: String,
+}
+
+impl<'a> RequestBuilder<'a> {
+    fn new(agent: &'a HttpAgent, method: Method, url: &str) -> Self {
+        Self {
+            agent,
+            method,
+            url: url.to_string(),
+        }
+    }
+
+    /// Sends the request, folding any new cookies back into the jar.
+    pub async fn call(self) -> anyhow::Result<reqwest::Response> {
+        let mut request = self.agent.client.request(self.method, &self.url);
+
+        for (name, value) in self.agent.default_headers.lock().unwrap().iter() {
+            request = request.header(name, value);
+        }
+
+        if let Some(cookie_header) = self.agent.jar.lock().unwrap().header() {
+            request = request.header(reqwest::header::COOKIE, cookie_header);
+        }
+
+        let response = request.send().await?;
+        self.agent.jar.lock().unwrap().capture(response.headers());
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue, SET_COOKIE};
+
+    fn headers_with_cookies(values: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append(SET_COOKIE, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn capture_extracts_name_and_value_and_drops_attributes() {
+        let mut jar = CookieJar::default();
+
+        jar.capture(&headers_with_cookies(&["session=abc123; Path=/; HttpOnly"]));
+
+        assert_eq!(jar.header().as_deref(), Some("session=abc123"));
+    }
+
+    #[test]
+    fn capture_merges_multiple_set_cookie_headers() {
+        let mut jar = CookieJar::default();
+
+        jar.capture(&headers_with_cookies(&["a=1", "b=2"]));
+
+        let header = jar.header().unwrap();
+        assert!(header.contains("a=1"));
+        assert!(header.contains("b=2"));
+        assert_eq!(header.split("; ").count(), 2);
+    }
+
+    #[test]
+    fn capture_overwrites_a_cookie_with_the_same_name() {
+        let mut jar = CookieJar::default();
+
+        jar.capture(&headers_with_cookies(&["session=old"]));
+        jar.capture(&headers_with_cookies(&["session=new"]));
+
+        assert_eq!(jar.header().as_deref(), Some("session=new"));
+    }
+
+    #[test]
+    fn header_is_none_for_an_empty_jar() {
+        let jar = CookieJar::default();
+
+        assert_eq!(jar.header(), None);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod api;
+pub mod http_agent;
+pub mod registry;
+pub mod test_case;
+pub mod user_service;
+
+pub use api::{router, AppState};
+pub use http_agent::HttpAgent;
+pub use registry::{TaskStats, TestRegistry, TestingTask};
+pub use test_case::{TestCase, TestState};
+pub use user_service::{User, UserService, UserStore};
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+/// Shared context threaded into every registered task when it runs.
+pub struct RunContext {
+    pub environment: String,
+}
+
+/// Outcome of a single task run: timing, pass/fail, and assertion counts.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct TaskStats {
+    pub duration: Duration,
+    pub success: bool,
+    pub assertions_run: u32,
+    pub assertions_failed: u32,
+}
+
+/// A single first-class QE check the agent can schedule and run.
+#[async_trait]
+pub trait TestingTask: Send + Sync {
+    fn name(&self) -> String;
+
+    async fn run(&self, ctx: &RunContext) -> anyhow::Result<TaskStats>;
+}
+
+/// Registry of tasks the engine runs as a uniform extension point.
+#[derive(Default)]
+pub struct TestRegistry {
+    tasks: Vec<Box<dyn TestingTask>>,
+}
+
+impl TestRegistry {
+    pub fn new() -> Self {
+        Self { tasks: Vec::new() }
+    }
+
+    pub fn register(&mut self, task: Box<dyn TestingTask>) {
+        self.tasks.push(task);
+    }
+
+    /// Runs every registered task, isolating failures so one bad task
+    /// doesn't abort the rest of the run.
+    pub async fn run_all(&self, ctx: &RunContext) -> HashMap<String, TaskStats> {
+        let mut results = HashMap::with_capacity(self.tasks.len());
+
+        for task in &self.tasks {
+            let name = task.name();
+            let started = Instant::now();
+
+            let stats = match task.run(ctx).await {
+                Ok(mut stats) => {
+                    stats.duration = started.elapsed();
+                    if stats.success {
+                        log::info!("task '{name}' passed in {:?}", stats.duration);
+                    } else {
+                        log::warn!("task '{name}' failed in {:?}", stats.duration);
+                    }
+                    stats
+                }
+                Err(err) => {
+                    log::error!("task '{name}' failed: {err:#}");
+                    TaskStats {
+                        duration: started.elapsed(),
+                        success: false,
+                        ..Default::default()
+                    }
+                }
+            };
+
+            results.insert(name, stats);
+        }
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ErroringTask;
+
+    #[async_trait]
+    impl TestingTask for ErroringTask {
+        fn name(&self) -> String {
+            "erroring".to_string()
+        }
+
+        async fn run(&self, _ctx: &RunContext) -> anyhow::Result<TaskStats> {
+            anyhow::bail!("boom")
+        }
+    }
+
+    struct FailingTask;
+
+    #[async_trait]
+    impl TestingTask for FailingTask {
+        fn name(&self) -> String {
+            "failing".to_string()
+        }
+
+        async fn run(&self, _ctx: &RunContext) -> anyhow::Result<TaskStats> {
+            Ok(TaskStats {
+                success: false,
+                assertions_run: 2,
+                assertions_failed: 1,
+                ..Default::default()
+            })
+        }
+    }
+
+    struct PassingTask;
+
+    #[async_trait]
+    impl TestingTask for PassingTask {
+        fn name(&self) -> String {
+            "passing".to_string()
+        }
+
+        async fn run(&self, _ctx: &RunContext) -> anyhow::Result<TaskStats> {
+            Ok(TaskStats {
+                success: true,
+                assertions_run: 1,
+                assertions_failed: 0,
+                ..Default::default()
+            })
+        }
+    }
+
+    fn ctx() -> RunContext {
+        RunContext {
+            environment: "test".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_failing_task_does_not_abort_the_rest_of_the_run() {
+        let mut registry = TestRegistry::new();
+        registry.register(Box::new(ErroringTask));
+        registry.register(Box::new(PassingTask));
+
+        let results = registry.run_all(&ctx()).await;
+
+        assert_eq!(results.len(), 2);
+        assert!(!results["erroring"].success);
+        assert!(results["passing"].success);
+    }
+
+    #[tokio::test]
+    async fn an_err_result_is_recorded_as_a_failed_stat() {
+        let mut registry = TestRegistry::new();
+        registry.register(Box::new(ErroringTask));
+
+        let results = registry.run_all(&ctx()).await;
+
+        let stats = &results["erroring"];
+        assert!(!stats.success);
+        assert_eq!(stats.assertions_run, 0);
+        assert_eq!(stats.assertions_failed, 0);
+    }
+
+    #[tokio::test]
+    async fn a_task_reporting_logical_failure_is_preserved_in_stats() {
+        let mut registry = TestRegistry::new();
+        registry.register(Box::new(FailingTask));
+
+        let results = registry.run_all(&ctx()).await;
+
+        let stats = &results["failing"];
+        assert!(!stats.success);
+        assert_eq!(stats.assertions_run, 2);
+        assert_eq!(stats.assertions_failed, 1);
+    }
+}
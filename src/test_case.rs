@@ -0,0 +1,308 @@
+/// A legal state in a `TestCase`'s lifecycle.
+///
+/// Every concrete state implements every transition; a transition that
+/// is not legal from a state (e.g. running a case that was never
+/// approved) returns that state unchanged, so illegal moves are no-ops
+/// rather than guarded by ad-hoc booleans. This is decided at run time:
+/// the compiler does not reject an illegal move.
+pub trait TestState: Send {
+    fn submit(self: Box<Self>) -> Box<dyn TestState>;
+    fn approve(self: Box<Self>) -> Box<dyn TestState>;
+    fn reject(self: Box<Self>) -> Box<dyn TestState>;
+    fn report(&self) -> &str;
+
+    /// Name of the concrete state, for tests to assert on since
+    /// `report()` alone can't distinguish the non-terminal states.
+    #[cfg(test)]
+    fn debug_name(&self) -> &'static str;
+}
+
+pub struct Draft;
+pub struct PendingReview;
+pub struct Approved;
+pub struct Running;
+pub struct Passed;
+pub struct Failed;
+
+impl TestState for Draft {
+    fn submit(self: Box<Self>) -> Box<dyn TestState> {
+        Box::new(PendingReview)
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn report(&self) -> &str {
+        ""
+    }
+
+    #[cfg(test)]
+    fn debug_name(&self) -> &'static str {
+        "Draft"
+    }
+}
+
+impl TestState for PendingReview {
+    fn submit(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn TestState> {
+        Box::new(Approved)
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn TestState> {
+        Box::new(Draft)
+    }
+
+    fn report(&self) -> &str {
+        ""
+    }
+
+    #[cfg(test)]
+    fn debug_name(&self) -> &'static str {
+        "PendingReview"
+    }
+}
+
+impl TestState for Approved {
+    fn submit(self: Box<Self>) -> Box<dyn TestState> {
+        Box::new(Running)
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn TestState> {
+        Box::new(Draft)
+    }
+
+    fn report(&self) -> &str {
+        ""
+    }
+
+    #[cfg(test)]
+    fn debug_name(&self) -> &'static str {
+        "Approved"
+    }
+}
+
+impl TestState for Running {
+    fn submit(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn TestState> {
+        Box::new(Passed)
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn TestState> {
+        Box::new(Failed)
+    }
+
+    fn report(&self) -> &str {
+        ""
+    }
+
+    #[cfg(test)]
+    fn debug_name(&self) -> &'static str {
+        "Running"
+    }
+}
+
+impl TestState for Passed {
+    fn submit(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn report(&self) -> &str {
+        "passed"
+    }
+
+    #[cfg(test)]
+    fn debug_name(&self) -> &'static str {
+        "Passed"
+    }
+}
+
+impl TestState for Failed {
+    fn submit(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn TestState> {
+        self
+    }
+
+    fn report(&self) -> &str {
+        "failed"
+    }
+
+    #[cfg(test)]
+    fn debug_name(&self) -> &'static str {
+        "Failed"
+    }
+}
+
+/// A QE test case tracked through its lifecycle by an explicit state
+/// machine rather than a status enum with ad-hoc guards.
+pub struct TestCase {
+    state: Option<Box<dyn TestState>>,
+}
+
+impl TestCase {
+    pub fn new() -> Self {
+        Self {
+            state: Some(Box::new(Draft)),
+        }
+    }
+
+    pub fn submit(&mut self) {
+        if let Some(state) = self.state.take() {
+            self.state = Some(state.submit());
+        }
+    }
+
+    pub fn approve(&mut self) {
+        if let Some(state) = self.state.take() {
+            self.state = Some(state.approve());
+        }
+    }
+
+    pub fn reject(&mut self) {
+        if let Some(state) = self.state.take() {
+            self.state = Some(state.reject());
+        }
+    }
+
+    pub fn report(&self) -> &str {
+        self.state.as_deref().map_or("", TestState::report)
+    }
+
+    #[cfg(test)]
+    fn debug_state(&self) -> &'static str {
+        self.state.as_deref().map_or("None", TestState::debug_name)
+    }
+}
+
+impl Default for TestCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draft_is_the_initial_state() {
+        let case = TestCase::new();
+
+        assert_eq!(case.debug_state(), "Draft");
+        assert_eq!(case.report(), "");
+    }
+
+    #[test]
+    fn approve_and_reject_are_no_ops_on_a_draft() {
+        let mut case = TestCase::new();
+
+        case.approve();
+        assert_eq!(case.debug_state(), "Draft");
+
+        case.reject();
+        assert_eq!(case.debug_state(), "Draft");
+    }
+
+    #[test]
+    fn the_happy_path_reaches_passed_and_reports_it() {
+        let mut case = TestCase::new();
+
+        case.submit();
+        assert_eq!(case.debug_state(), "PendingReview");
+        assert_eq!(case.report(), "");
+
+        case.approve();
+        assert_eq!(case.debug_state(), "Approved");
+        assert_eq!(case.report(), "");
+
+        case.submit();
+        assert_eq!(case.debug_state(), "Running");
+        assert_eq!(case.report(), "");
+
+        case.approve();
+        assert_eq!(case.debug_state(), "Passed");
+        assert_eq!(case.report(), "passed");
+    }
+
+    #[test]
+    fn a_running_case_can_be_rejected_into_failed() {
+        let mut case = TestCase::new();
+
+        case.submit();
+        case.approve();
+        case.submit();
+        case.reject();
+
+        assert_eq!(case.debug_state(), "Failed");
+        assert_eq!(case.report(), "failed");
+    }
+
+    #[test]
+    fn pending_review_can_be_sent_back_to_draft() {
+        let mut case = TestCase::new();
+
+        case.submit();
+        case.reject();
+
+        assert_eq!(case.debug_state(), "Draft");
+        assert_eq!(case.report(), "");
+    }
+
+    #[test]
+    fn passed_and_failed_are_terminal() {
+        let mut case = TestCase::new();
+        case.submit();
+        case.approve();
+        case.submit();
+        case.approve();
+        assert_eq!(case.debug_state(), "Passed");
+
+        case.submit();
+        case.approve();
+        case.reject();
+        assert_eq!(case.debug_state(), "Passed");
+        assert_eq!(case.report(), "passed");
+
+        let mut failed = TestCase::new();
+        failed.submit();
+        failed.approve();
+        failed.submit();
+        failed.reject();
+        assert_eq!(failed.debug_state(), "Failed");
+
+        failed.submit();
+        failed.approve();
+        failed.reject();
+        assert_eq!(failed.debug_state(), "Failed");
+        assert_eq!(failed.report(), "failed");
+    }
+}
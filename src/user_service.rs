@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+}
+
+/// Storage surface for users, extracted so agent logic can depend on
+/// `Arc<dyn UserStore>` and swap in a mock backing store during tests.
+/// Takes `&self` with internal locking (mirroring `HttpAgent`'s use of
+/// `Mutex` for shared mutable state) so the trait stays usable behind a
+/// plain `Arc`, the same way consumers like `AppState` hold it.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait UserStore: Send + Sync {
+    async fn get_user(&self, id: &str) -> Option<User>;
+    async fn put_user(&self, user: User);
+}
+
+/// `HashMap`-backed `UserStore` used outside of tests.
+#[derive(Default)]
+pub struct UserService {
+    users: Mutex<HashMap<String, User>>,
+}
+
+impl UserService {
+    pub fn new() -> Self {
+        Self {
+            users: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl UserStore for UserService {
+    async fn get_user(&self, id: &str) -> Option<User> {
+        self.users.lock().unwrap().get(id).cloned()
+    }
+
+    async fn put_user(&self, user: User) {
+        self.users.lock().unwrap().insert(user.id.clone(), user);
+    }
+}
+
+/// Looks up a user through any `UserStore`, decoupled from the concrete
+/// backing store so it can be exercised against a real or mock service.
+pub async fn find_user(store: &Arc<dyn UserStore>, id: &str) -> Option<User> {
+    store.get_user(id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_user() -> User {
+        User {
+            id: "1".to_string(),
+            name: "Ada".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn find_user_against_real_store() {
+        let service = UserService::new();
+        service.put_user(sample_user()).await;
+        let store: Arc<dyn UserStore> = Arc::new(service);
+
+        let found = find_user(&store, "1").await;
+
+        assert_eq!(found, Some(sample_user()));
+    }
+
+    #[tokio::test]
+    async fn put_user_is_callable_through_a_shared_arc() {
+        let store: Arc<dyn UserStore> = Arc::new(UserService::new());
+
+        store.put_user(sample_user()).await;
+
+        assert_eq!(find_user(&store, "1").await, Some(sample_user()));
+    }
+
+    #[tokio::test]
+    async fn find_user_against_mock_store() {
+        let mut mock = MockUserStore::new();
+        mock.expect_get_user()
+            .withf(|id| id == "1")
+            .returning(|_| Some(sample_user()));
+        let store: Arc<dyn UserStore> = Arc::new(mock);
+
+        let found = find_user(&store, "1").await;
+
+        assert_eq!(found, Some(sample_user()));
+    }
+
+    #[tokio::test]
+    async fn find_user_returns_none_on_mock_miss() {
+        let mut mock = MockUserStore::new();
+        mock.expect_get_user().returning(|_| None);
+        let store: Arc<dyn UserStore> = Arc::new(mock);
+
+        let found = find_user(&store, "missing").await;
+
+        assert_eq!(found, None);
+    }
+}